@@ -89,6 +89,99 @@ pub fn skip_then_retry_until<I, const N: usize>(until: [I; N]) -> SkipThenRetryU
     SkipThenRetryUntil(until, false, false)
 }
 
+/// See [`repair_with`].
+#[derive(Copy, Clone)]
+pub struct RepairWith<I, const N: usize>(pub(crate) [I; N], pub(crate) usize);
+
+impl<I, const N: usize> RepairWith<I, N> {
+    /// Sets the maximum number of token-level repairs that may be applied within a single failing region.
+    ///
+    /// Raising this permits recovery from clusters of nearby mistakes at the cost of potentially cascading repairs on
+    /// badly broken input. The default is `1`, matching the single-token edits that most compilers apply.
+    pub fn with_max_repairs(self, max_repairs: usize) -> Self {
+        Self(self.0, max_repairs)
+    }
+}
+
+impl<I: Clone + PartialEq, O, E: Error<I>, const N: usize> Strategy<I, O, E> for RepairWith<I, N> {
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        mut a_errors: Vec<Located<I, P::Error>>,
+        a_err: Located<I, P::Error>,
+        parser: P,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        // Remember where recovery began so that each candidate can be scored from the same position and so that, if no
+        // candidate succeeds, we can leave the stream exactly where we found it.
+        let start = stream.save();
+
+        // Score each candidate repair — deleting the leading 1..=`self.1` tokens of the failing region — by how far
+        // the wrapped parser subsequently progresses, and keep the one that advances furthest. Each candidate is rolled
+        // back via `save`/`revert` before the next is tried. Only deletions are tried: no insertion or substitution
+        // edit is attempted.
+        let mut best_skip = None;
+        let mut best_advance = 0;
+        for skip in 1..=self.1 {
+            stream.revert(start);
+            let mut out_of_input = false;
+            for _ in 0..skip {
+                if stream.next().2.is_none() {
+                    out_of_input = true;
+                    break;
+                }
+            }
+            if out_of_input {
+                break;
+            }
+            #[allow(deprecated)]
+            let (_errors, res) = debugger.invoke(&parser, stream);
+            if res.is_ok() {
+                let advance = stream.save();
+                if best_skip.is_none() || advance > best_advance {
+                    best_skip = Some(skip);
+                    best_advance = advance;
+                }
+            }
+        }
+
+        stream.revert(start);
+        match best_skip {
+            // No number of leading deletions let the parser succeed: surface the original error untouched.
+            None => (a_errors, Err(a_err)),
+            Some(skip) => {
+                // Re-apply the winning deletion for real, emitting one error per deleted token to describe the repair.
+                for _ in 0..skip {
+                    let (at, span, tok) = stream.next();
+                    a_errors.push(Located::at(
+                        at,
+                        E::expected_input_found(span, self.0.iter().cloned().map(Some), tok),
+                    ));
+                }
+                #[allow(deprecated)]
+                let (mut errors, res) = debugger.invoke(&parser, stream);
+                a_errors.append(&mut errors);
+                (a_errors, res)
+            }
+        }
+    }
+}
+
+/// A recovery strategy that repairs an error by deleting offending tokens, a restricted form of the single-token
+/// repairs popularised by production compilers such as `rustc`.
+///
+/// On a fatal error the strategy considers deleting the leading 1..=`n` tokens of the failing region (where `n` is the
+/// cap set by [`RepairWith::with_max_repairs`], `1` by default), scores each candidate by how far the wrapped parser
+/// then progresses, and keeps the deletion that advances furthest. Candidates are rolled back between attempts, so a
+/// larger deletion is only chosen when it genuinely lets the parser get further. If no deletion lets the parser
+/// succeed, the original error is surfaced and the stream is left untouched.
+///
+/// Only deletions are scored; this strategy does not attempt to insert or substitute tokens. `expected` is the set of
+/// tokens the parser was expecting, used only to describe each deleted token in the synthesised errors.
+pub fn repair_with<I, const N: usize>(expected: [I; N]) -> RepairWith<I, N> {
+    RepairWith(expected, 1)
+}
+
 /// See [`skip_until`].
 #[derive(Copy, Clone)]
 pub struct SkipUntil<I, F, const N: usize>(
@@ -170,6 +263,161 @@ pub fn skip_until<I, F, const N: usize>(until: [I; N], fallback: F) -> SkipUntil
     SkipUntil(until, fallback, false, false)
 }
 
+/// See [`skip_until_separator`].
+#[derive(Copy, Clone)]
+pub struct SkipUntilSeparator<I, F, const N: usize>(
+    pub(crate) I,
+    pub(crate) [I; N],
+    pub(crate) F,
+);
+
+impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize> Strategy<I, O, E>
+    for SkipUntilSeparator<I, F, N>
+{
+    fn recover<D: Debugger, P: Parser<I, O, Error = E>>(
+        &self,
+        mut a_errors: Vec<Located<I, P::Error>>,
+        a_err: Located<I, P::Error>,
+        _parser: P,
+        _debugger: &mut D,
+        stream: &mut StreamOf<I, P::Error>,
+    ) -> PResult<I, O, P::Error> {
+        let pre_state = stream.save();
+
+        // A spurious or trailing separator sitting where an element should be: consume it and report it so that the
+        // list can carry on with whatever follows rather than treating the separator as a broken element.
+        let spurious = stream.attempt(|stream| {
+            let is_sep = stream.next().2.as_ref() == Some(&self.0);
+            (is_sep, is_sep)
+        });
+        if spurious {
+            a_errors.push(Located::at(
+                a_err.at,
+                E::expected_input_found(
+                    stream.span_since(pre_state),
+                    self.1.iter().cloned().map(Some),
+                    Some(self.0.clone()),
+                ),
+            ));
+            return (a_errors, Ok(((self.2)(stream.span_since(pre_state)), None)));
+        }
+
+        // Otherwise the element itself is broken (e.g. two elements run together with a missing separator):
+        // synthesise a targeted "expected separator" diagnostic at the offending token, then resynchronise by scanning
+        // forward to the next separator or list terminator, leaving that token in the stream so the surrounding list
+        // resumes from it rather than discarding every following element.
+        let found = stream.attempt(|stream| {
+            let (_, span, tok) = stream.next();
+            (false, (span, tok))
+        });
+        a_errors.push(Located::at(
+            a_err.at,
+            E::expected_input_found(
+                found.0,
+                core::iter::once(self.0.clone())
+                    .chain(self.1.iter().cloned())
+                    .map(Some),
+                found.1,
+            ),
+        ));
+        loop {
+            let synced = stream.attempt(|stream| match stream.next().2 {
+                Some(t) if t == self.0 => (false, true),
+                Some(t) if self.1.contains(&t) => (false, true),
+                Some(_) => (true, false),
+                None => (false, true),
+            });
+            if synced {
+                break (a_errors, Ok(((self.2)(stream.span_since(pre_state)), None)));
+            }
+        }
+    }
+}
+
+/// A recovery strategy for the elements of a delimited list, analogous to the comma recovery performed by compilers
+/// such as `rustc`.
+///
+/// Unlike [`skip_until`], this strategy understands the structure of a separated sequence: on a failed element it
+/// resynchronises to the next `separator` or one of the list `terminators`, emits a targeted error (a missing separator
+/// where two elements run together, or an unexpected trailing separator), and yields a fallback element for the broken
+/// slot so that parsing of the remaining elements can resume.
+///
+/// The intended surface is to wrap the *element* parser of a separated sequence with [`Parser::recover_with`], e.g.
+/// `elem.recover_with(skip_until_separator(',', [')'], ..)).separated_by(just(','))`, rather than wrapping the
+/// `separated_by(...)` sequence as a whole. Recovering per element is what lets the list continue past a single bad
+/// element; recovering the whole sequence at once would discard every following element and defeat the purpose. A
+/// `SeparatedBy`-level convenience would be exactly this element wrapper under the hood.
+///
+/// A function that generates a fallback output on recovery is also required.
+pub fn skip_until_separator<I, F, const N: usize>(
+    separator: I,
+    terminators: [I; N],
+    fallback: F,
+) -> SkipUntilSeparator<I, F, N> {
+    SkipUntilSeparator(separator, terminators, fallback)
+}
+
+/// A side channel that records unmatched delimiter diagnostics during a parse so that they can be reconciled into one
+/// best error per mismatch rather than reported eagerly at each site.
+///
+/// Emitting an `unclosed_delimiter` error the moment a mismatch is seen — as [`NestedDelimiters`] does by default —
+/// produces duplicate and cascading complaints in real grammars, since the same physically-unmatched opener is
+/// rediscovered by every enclosing recovery. Deferring the diagnostics here and reconciling them at the end lets the
+/// recovery subsystem emit exactly one best error per genuine mismatch, the same approach compilers such as `rustc`
+/// take by delaying unmatched-delimiter diagnostics until after parsing.
+pub struct DelimiterBalancer<I, E: Error<I>> {
+    candidates: Vec<(usize, Located<I, E>)>,
+}
+
+impl<I, E: Error<I>> Default for DelimiterBalancer<I, E> {
+    fn default() -> Self {
+        Self {
+            candidates: Vec::new(),
+        }
+    }
+}
+
+impl<I, E: Error<I>> DelimiterBalancer<I, E> {
+    /// Defer an unmatched-delimiter diagnostic, tagged with the delimiter `depth` at which it was discovered. Deeper
+    /// (more deeply nested) candidates refer to more specific, innermost mismatches.
+    pub fn defer(&mut self, depth: usize, error: Located<I, E>) {
+        self.candidates.push((depth, error));
+    }
+
+    /// Reconcile all deferred diagnostics into at most one error per input position.
+    ///
+    /// When several candidates were discovered at the same input position (the same `at`), only the innermost
+    /// (deepest) is kept, the first on a tie; the shallower complaints at that position are suppressed. Candidates at
+    /// different positions are all kept, even when they name the same opener.
+    pub fn reconcile(self) -> Vec<Located<I, E>> {
+        let mut best: Vec<(usize, Located<I, E>)> = Vec::new();
+        'candidates: for (depth, error) in self.candidates {
+            for slot in best.iter_mut() {
+                if slot.1.at == error.at {
+                    if depth > slot.0 {
+                        *slot = (depth, error);
+                    }
+                    continue 'candidates;
+                }
+            }
+            best.push((depth, error));
+        }
+        best.into_iter().map(|(_, error)| error).collect()
+    }
+
+    /// Reduce the deferred diagnostics to the single innermost (deepest) mismatch, if any.
+    ///
+    /// This is the right choice when reconciling the mismatches of one delimited region in isolation: there is a
+    /// single genuine unmatched opener, and the innermost complaint localises it best. Reconciling across independent
+    /// regions of a whole parse instead wants [`DelimiterBalancer::reconcile`], which keeps one error per position.
+    pub fn into_innermost(self) -> Option<Located<I, E>> {
+        self.candidates
+            .into_iter()
+            .max_by_key(|(depth, _)| *depth)
+            .map(|(_, error)| error)
+    }
+}
+
 /// See [`nested_delimiters`].
 #[derive(Copy, Clone)]
 pub struct NestedDelimiters<I, F, const N: usize>(
@@ -177,8 +425,21 @@ pub struct NestedDelimiters<I, F, const N: usize>(
     pub(crate) I,
     pub(crate) [(I, I); N],
     pub(crate) F,
+    pub(crate) bool,
 );
 
+impl<I, F, const N: usize> NestedDelimiters<I, F, N> {
+    /// Opt this recovery into deferred, deduplicated delimiter-mismatch reporting.
+    ///
+    /// In deferred mode every mismatch found while scanning the region is collected in a [`DelimiterBalancer`] and
+    /// only the innermost one is reported (the last found, where several are equally deep), rather than the first one
+    /// found. The balancer is local to each recovery: errors pushed by enclosing recoveries are not deduplicated
+    /// against it, so each enclosing recovery can still report its own error.
+    pub fn deferred(self) -> Self {
+        Self(self.0, self.1, self.2, self.3, true)
+    }
+}
+
 impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize> Strategy<I, O, E>
     for NestedDelimiters<I, F, N>
 {
@@ -197,6 +458,9 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
         let mut balance_others = [0; N];
         let mut starts = Vec::new();
         let mut error = None;
+        // In deferred mode every mismatch found during this scan is collected here and reconciled to a single best
+        // error at the end, rather than eagerly keeping only the first via `error`.
+        let mut balancer: DelimiterBalancer<I, P::Error> = DelimiterBalancer::default();
         let pre_state = stream.save();
         let recovered = loop {
             if match stream.next() {
@@ -219,18 +483,21 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
 
                             if *balance_other < 0 && balance == 1 {
                                 // stream.revert(pre_state);
-                                error.get_or_insert_with(|| {
-                                    Located::at(
-                                        at,
-                                        P::Error::unclosed_delimiter(
-                                            starts.pop().unwrap(),
-                                            self.0.clone(),
-                                            span.clone(),
-                                            self.1.clone(),
-                                            Some(t.clone()),
-                                        ),
-                                    )
-                                });
+                                let located = Located::at(
+                                    at,
+                                    P::Error::unclosed_delimiter(
+                                        starts.last().cloned().unwrap(),
+                                        self.0.clone(),
+                                        span.clone(),
+                                        self.1.clone(),
+                                        Some(t.clone()),
+                                    ),
+                                );
+                                if self.4 {
+                                    balancer.defer(starts.len(), located);
+                                } else {
+                                    error.get_or_insert(located);
+                                }
                             }
                         }
                     }
@@ -238,7 +505,7 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
                 }
                 (at, span, None) => {
                     if balance > 0 && balance == 1 {
-                        error.get_or_insert_with(|| match starts.pop() {
+                        let located = match starts.last().cloned() {
                             Some(start) => Located::at(
                                 at,
                                 P::Error::unclosed_delimiter(
@@ -257,7 +524,12 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
                                     None,
                                 ),
                             ),
-                        });
+                        };
+                        if self.4 {
+                            balancer.defer(starts.len(), located);
+                        } else {
+                            error.get_or_insert(located);
+                        }
                     }
                     break false;
                 }
@@ -274,7 +546,14 @@ impl<I: Clone + PartialEq, O, F: Fn(E::Span) -> O, E: Error<I>, const N: usize>
             }
         };
 
-        if let Some(e) = error {
+        if self.4 {
+            // Deferred mode: collapse the mismatches found in this region to the single innermost error, so we never
+            // emit more than the eager path (at most one). The balancer is local to this call; it is not shared with
+            // enclosing recoveries, so their errors are not deduplicated against this one.
+            if let Some(e) = balancer.into_innermost() {
+                a_errors.push(e);
+            }
+        } else if let Some(e) = error {
             a_errors.push(e);
         }
 
@@ -306,7 +585,7 @@ pub fn nested_delimiters<I: PartialEq, F, const N: usize>(
         start != end,
         "Start and end delimiters cannot be the same when using `NestedDelimiters`"
     );
-    NestedDelimiters(start, end, others, fallback)
+    NestedDelimiters(start, end, others, fallback, false)
 }
 
 /// A parser that includes a fallback recovery strategy should parsing result in an error.
@@ -390,3 +669,209 @@ impl<I: Clone, O, A: Parser<I, O, Error = E>, B: Parser<I, O, Error = E>, E: Err
         self.parse_inner(d, s)
     }
 }
+
+/// Indicates whether a parser's output was produced by a clean parse or reconstructed via error recovery.
+///
+/// Borrowed from the move compilers such as `rustc` made away from ad-hoc `bool`/`Option<ErrorGuaranteed>` flags, this
+/// lets downstream consumers distinguish recovered subtrees from genuine ones. The invariant is that [`Recovered::Yes`]
+/// is only ever produced when at least one error was emitted to reach the output, so consumers may safely skip
+/// semantic checks (name resolution, type checking, ...) on recovered nodes without re-deriving that fact.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Recovered {
+    /// The output comes from a clean parse with no recovery applied.
+    No,
+    /// The output was reconstructed by error recovery; at least one error was emitted to reach it.
+    Yes,
+}
+
+/// A parser that pairs the output of a recovering parser with a [`Recovered`] marker describing whether recovery took
+/// place, mapping the two into a new output.
+#[derive(Copy, Clone)]
+pub struct MapWithRecovered<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, U, A: Parser<I, O, Error = E>, F: Fn(O, Recovered) -> U, E: Error<I>> Parser<I, U>
+    for MapWithRecovered<A, F>
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        let (errors, res) = debugger.invoke(&self.0, stream);
+        match res {
+            // A successful parse that nonetheless emitted errors can only have got there by recovery, which upholds
+            // the `Recovered::Yes` invariant that at least one error was pushed.
+            Ok((out, alt)) => {
+                let recovered = if errors.is_empty() {
+                    Recovered::No
+                } else {
+                    Recovered::Yes
+                };
+                (errors, Ok(((self.1)(out, recovered), alt)))
+            }
+            Err(err) => (errors, Err(err)),
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, U, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// Map the output of a recovering parser together with a [`Recovered`] marker describing whether the output was
+/// produced cleanly or via error recovery.
+///
+/// This is typically wrapped around a parser built with [`Parser::recover_with`] so that downstream consumers can tell
+/// which subtrees came from recovery and skip semantic checks on them — see [`Recovered`] for the guarantee that backs
+/// this.
+///
+/// It is available only as this free function, `map_with_recovered(parser, f)`; there is no `Parser` method for it.
+///
+/// The `Recovered::No` / `Recovered::Yes` split is decided purely from whether the inner parse committed any errors:
+/// in this crate a parse only returns `Ok` with a non-empty error list when those errors were produced by recovery, so
+/// an empty list is a faithful witness of a clean parse and a non-empty one upholds the [`Recovered::Yes`] invariant.
+pub fn map_with_recovered<I: Clone, O, U, A, F, E>(parser: A, f: F) -> MapWithRecovered<A, F>
+where
+    A: Parser<I, O, Error = E>,
+    F: Fn(O, Recovered) -> U,
+    E: Error<I>,
+{
+    MapWithRecovered(parser, f)
+}
+
+/// Error types that can be reconstructed from the context in which stream-level recovery occurred, modelled on
+/// winnow's `FromRecoverableError`.
+///
+/// When a leaf parser failure is absorbed at the stream boundary (see [`StreamRecovery`]), the recovered error is built
+/// from three pieces of context: the span at which recovery began, the span at which parsing resumed, and the original
+/// error that triggered recovery. Implementing this lets error types attach richer "recovered between X and Y"
+/// diagnostics instead of surfacing only the original leaf error.
+pub trait FromRecoverableError<I>: Error<I> {
+    /// Reconstruct an error from the span where recovery started, the span where it resumed, and the original error.
+    fn from_recoverable_error(
+        recovery_start: Self::Span,
+        recovery_resumed: Self::Span,
+        original: Self,
+    ) -> Self;
+}
+
+/// A log of the errors recovered at a parse boundary, reconstructed from the context in which recovery occurred.
+///
+/// Modelled on winnow's `Recover` stream trait, this is the piece of state that a stream-integrated recovery mode
+/// accumulates: whenever a leaf parser failure is absorbed via [`StreamRecovery::absorb`], the reconstructed error is
+/// pushed here and parsing continues with a sentinel value. A [`StreamOf`] does not own one of these logs: it is used
+/// only by the [`StreamRecovered`] wrapper, so only failures of the parsers it wraps are absorbed. There is
+/// deliberately no enable/disable flag: a `StreamRecovery` only exists where recovery is already wanted, so absorption
+/// is unconditional.
+pub struct StreamRecovery<I, E: Error<I>> {
+    errors: Vec<Located<I, E>>,
+}
+
+impl<I, E: Error<I>> Default for StreamRecovery<I, E> {
+    fn default() -> Self {
+        Self { errors: Vec::new() }
+    }
+}
+
+impl<I, E: Error<I>> StreamRecovery<I, E> {
+    /// Absorb a leaf-parser failure, logging an error reconstructed from the recovered region via
+    /// [`FromRecoverableError`] so that the parse can continue with a sentinel value.
+    ///
+    /// `recovery_start` is the span over which the failed parser ran, `recovery_resumed` the span at which parsing
+    /// picks back up, and `original` the error that triggered recovery.
+    pub fn absorb(
+        &mut self,
+        at: usize,
+        recovery_start: E::Span,
+        recovery_resumed: E::Span,
+        original: E,
+    ) where
+        E: FromRecoverableError<I>,
+    {
+        let error = E::from_recoverable_error(recovery_start, recovery_resumed, original);
+        self.errors.push(Located::at(at, error));
+    }
+
+    /// The errors absorbed over the course of the parse.
+    pub fn into_errors(self) -> Vec<Located<I, E>> {
+        self.errors
+    }
+}
+
+/// A parser that absorbs the failure of a wrapped parser through a [`StreamRecovery`], continuing with a sentinel
+/// value instead of propagating the error.
+///
+/// This is a *static* wrapper: it absorbs failures only of the parser it wraps, at the boundary where it sits. On
+/// failure it skips one token to resynchronise, reconstructs the error via [`FromRecoverableError`] from the span the
+/// failed parser ran over (`recovery_start`) and the span at which it resumes (`recovery_resumed`) — carrying the
+/// original error through unchanged — logs it, and yields a sentinel so that the rest of the input can parse. The
+/// [`StreamOf`] holds no [`StreamRecovery`] of its own, so failures of parsers that are not wrapped this way are not
+/// absorbed.
+#[derive(Copy, Clone)]
+pub struct StreamRecovered<A, F>(pub(crate) A, pub(crate) F);
+
+impl<I: Clone, O, A, F, E> Parser<I, O> for StreamRecovered<A, F>
+where
+    A: Parser<I, O, Error = E>,
+    F: Fn(E::Span) -> O,
+    E: Error<I> + FromRecoverableError<I>,
+{
+    type Error = E;
+
+    fn parse_inner<D: Debugger>(
+        &self,
+        debugger: &mut D,
+        stream: &mut StreamOf<I, E>,
+    ) -> PResult<I, O, E> {
+        let start = stream.save();
+        #[allow(deprecated)]
+        let (mut errors, res) = debugger.invoke(&self.0, stream);
+        match res {
+            Ok(out) => (errors, Ok(out)),
+            Err(err) => {
+                // The span the failed parser ran over before giving up.
+                let recovery_start = stream.span_since(start);
+                // Resynchronise by skipping a single token so the caller makes forward progress and does not re-enter
+                // recovery at the same position; the span then covers up to where parsing resumes.
+                let _ = stream.next();
+                let recovery_resumed = stream.span_since(start);
+                // Absorb the leaf failure: reconstruct an error from the original and the start/resume spans, log it in
+                // the recovery channel, and continue with a sentinel so the rest of the input can parse.
+                let mut recovery = StreamRecovery::<I, E>::default();
+                recovery.absorb(err.at, recovery_start, recovery_resumed.clone(), err.error);
+                errors.append(&mut recovery.into_errors());
+                (errors, Ok(((self.1)(recovery_resumed), None)))
+            }
+        }
+    }
+
+    fn parse_inner_verbose(&self, d: &mut Verbose, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+    fn parse_inner_silent(&self, d: &mut Silent, s: &mut StreamOf<I, E>) -> PResult<I, O, E> {
+        #[allow(deprecated)]
+        self.parse_inner(d, s)
+    }
+}
+
+/// Wrap a parser so that its failure is absorbed through a [`StreamRecovery`], yielding a sentinel produced by
+/// `fallback` from the recovered span instead of propagating the error.
+///
+/// See [`StreamRecovered`] and [`FromRecoverableError`] for the reconstruction the recovered error undergoes.
+pub fn recover_at_stream<I: Clone, O, A, F, E>(parser: A, fallback: F) -> StreamRecovered<A, F>
+where
+    A: Parser<I, O, Error = E>,
+    F: Fn(E::Span) -> O,
+    E: Error<I> + FromRecoverableError<I>,
+{
+    StreamRecovered(parser, fallback)
+}